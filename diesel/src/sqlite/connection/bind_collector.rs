@@ -0,0 +1,79 @@
+use libsqlite3_sys as ffi;
+
+use crate::result::QueryResult;
+
+/// A value that has been serialized for binding to a parameter of a
+/// prepared SQLite statement.
+///
+/// Most variants carry the value itself (borrowed text and blobs are bound
+/// without a copy, owned ones are copied by SQLite), but
+/// [`Zeroblob`](Self::Zeroblob) is special: it carries only a length, and
+/// the statement binder asks SQLite to allocate and zero-fill that many
+/// bytes itself, so no `[u8]` buffer is ever materialized on the Rust side.
+#[derive(Debug, PartialEq)]
+pub enum SqliteBindValue<'a> {
+    BorrowedString(&'a str),
+    String(String),
+    BorrowedBinary(&'a [u8]),
+    Binary(Vec<u8>),
+    I32(i32),
+    I64(i64),
+    Double(f64),
+    Zeroblob(i32),
+    Null,
+}
+
+impl<'a> SqliteBindValue<'a> {
+    /// Binds `self` to the `idx`-th parameter (1-based) of `stmt`.
+    pub(crate) unsafe fn bind_to(
+        self,
+        stmt: *mut ffi::sqlite3_stmt,
+        idx: i32,
+    ) -> QueryResult<()> {
+        let result = match self {
+            SqliteBindValue::BorrowedString(s) => ffi::sqlite3_bind_text(
+                stmt,
+                idx,
+                s.as_ptr() as *const _,
+                s.len() as i32,
+                ffi::SQLITE_STATIC(),
+            ),
+            SqliteBindValue::String(s) => ffi::sqlite3_bind_text(
+                stmt,
+                idx,
+                s.as_ptr() as *const _,
+                s.len() as i32,
+                ffi::SQLITE_TRANSIENT(),
+            ),
+            SqliteBindValue::BorrowedBinary(b) => ffi::sqlite3_bind_blob(
+                stmt,
+                idx,
+                b.as_ptr() as *const _,
+                b.len() as i32,
+                ffi::SQLITE_STATIC(),
+            ),
+            SqliteBindValue::Binary(b) => ffi::sqlite3_bind_blob(
+                stmt,
+                idx,
+                b.as_ptr() as *const _,
+                b.len() as i32,
+                ffi::SQLITE_TRANSIENT(),
+            ),
+            SqliteBindValue::I32(x) => ffi::sqlite3_bind_int(stmt, idx, x),
+            SqliteBindValue::I64(x) => ffi::sqlite3_bind_int64(stmt, idx, x),
+            SqliteBindValue::Double(x) => ffi::sqlite3_bind_double(stmt, idx, x),
+            // No buffer to copy: ask SQLite to reserve `len` zero-filled bytes itself.
+            SqliteBindValue::Zeroblob(len) => ffi::sqlite3_bind_zeroblob(stmt, idx, len),
+            SqliteBindValue::Null => ffi::sqlite3_bind_null(stmt, idx),
+        };
+
+        if result == ffi::SQLITE_OK {
+            Ok(())
+        } else {
+            Err(crate::result::Error::DatabaseError(
+                crate::result::DatabaseErrorKind::Unknown,
+                Box::new(format!("Binding statement parameter {idx} failed")),
+            ))
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod bind_collector;
+
+pub(crate) use self::bind_collector::SqliteBindValue;
@@ -0,0 +1,368 @@
+use super::super::connection::SqliteValue;
+use super::super::Sqlite;
+use super::JsonValidFlags;
+use crate::deserialize::{self, FromSql};
+use crate::query_builder::QueryId;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{self, SqlType};
+use crate::sql_function;
+
+sql_function! {
+    /// Calls the SQLite `json_valid(x, flags)` function, checking whether `x`
+    /// is well-formed according to the given [`JsonValidFlag`](super::JsonValidFlag).
+    fn json_valid(x: sql_types::Text, flags: JsonValidFlags) -> sql_types::Bool;
+}
+
+sql_function! {
+    /// Calls the SQLite `jsonb(x)` function, converting a JSON text or JSONB
+    /// value `x` into the compact binary JSONB encoding.
+    fn jsonb(x: sql_types::Text) -> Jsonb;
+}
+
+sql_function! {
+    /// Calls the SQLite `json_extract(x, path)` function, extracting the
+    /// value at `path` from the text JSON document `x`.
+    fn json_extract(x: sql_types::Text, path: sql_types::Text) -> sql_types::Text;
+}
+
+sql_function! {
+    /// Calls the SQLite `jsonb_extract(x, path)` function, extracting the
+    /// value at `path` from `x` and returning it as JSONB.
+    fn jsonb_extract(x: sql_types::Text, path: sql_types::Text) -> Jsonb;
+}
+
+sql_function! {
+    /// Calls the SQLite `json_type(x)` function, returning the type of the
+    /// outermost element of the JSON document `x` (one of `"null"`, `"true"`,
+    /// `"false"`, `"integer"`, `"real"`, `"text"`, `"array"`, or `"object"`).
+    fn json_type(x: sql_types::Text) -> sql_types::Text;
+}
+
+/// The SQLite JSONB binary storage format.
+///
+/// JSONB is SQLite's compact binary encoding for JSON, produced by the
+/// [`jsonb`] function. Storing [`Jsonb`] columns instead of `Text`/`Json`
+/// avoids re-parsing JSON text on every read and is generally smaller on
+/// disk.
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(sqlite_type(name = "Binary"))]
+#[cfg(feature = "sqlite")]
+pub struct Jsonb;
+
+#[cfg(feature = "sqlite")]
+impl FromSql<Jsonb, Sqlite> for serde_json::Value {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let bytes = value.read_blob();
+        let (value, rest) = decode_element(bytes)?;
+        if !rest.is_empty() {
+            return Err("Unexpected trailing bytes in JSONB value".into());
+        }
+        Ok(value)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ToSql<Jsonb, Sqlite> for serde_json::Value {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        let mut bytes = Vec::new();
+        encode_element(self, &mut bytes);
+        out.set_value(bytes);
+        Ok(IsNull::No)
+    }
+}
+
+// JSONB element type tags, as defined by SQLite's on-disk JSONB format.
+const JSONB_NULL: u8 = 0;
+const JSONB_TRUE: u8 = 1;
+const JSONB_FALSE: u8 = 2;
+const JSONB_INT: u8 = 3;
+const JSONB_INT5: u8 = 4;
+const JSONB_FLOAT: u8 = 5;
+const JSONB_FLOAT5: u8 = 6;
+const JSONB_TEXT: u8 = 7;
+const JSONB_TEXTJ: u8 = 8;
+const JSONB_TEXT5: u8 = 9;
+const JSONB_TEXTRAW: u8 = 10;
+const JSONB_ARRAY: u8 = 11;
+const JSONB_OBJECT: u8 = 12;
+
+fn encode_header(tag: u8, len: usize, out: &mut Vec<u8>) {
+    if len <= 11 {
+        out.push(tag | ((len as u8) << 4));
+    } else if let Ok(len) = u8::try_from(len) {
+        out.push(tag | (12 << 4));
+        out.push(len);
+    } else if let Ok(len) = u16::try_from(len) {
+        out.push(tag | (13 << 4));
+        out.extend_from_slice(&len.to_be_bytes());
+    } else {
+        out.push(tag | (14 << 4));
+        out.extend_from_slice(&(len as u32).to_be_bytes());
+    }
+}
+
+fn encode_element(value: &serde_json::Value, out: &mut Vec<u8>) {
+    match value {
+        serde_json::Value::Null => encode_header(JSONB_NULL, 0, out),
+        serde_json::Value::Bool(true) => encode_header(JSONB_TRUE, 0, out),
+        serde_json::Value::Bool(false) => encode_header(JSONB_FALSE, 0, out),
+        serde_json::Value::Number(n) => {
+            let tag = if n.is_i64() || n.is_u64() {
+                JSONB_INT
+            } else {
+                JSONB_FLOAT
+            };
+            let text = n.to_string();
+            encode_header(tag, text.len(), out);
+            out.extend_from_slice(text.as_bytes());
+        }
+        serde_json::Value::String(s) => {
+            // `json_escape_body` gives us the JSON-escaped form without the
+            // surrounding quotes; when it matches the input verbatim, the
+            // string needs no escaping and can be stored as plain TEXT(7).
+            // Otherwise it must be stored as TEXTJ(8) with the escapes kept,
+            // or `json_valid`/`json_extract` would treat the blob as malformed.
+            let escaped = json_escape_body(s);
+            if escaped == *s {
+                encode_header(JSONB_TEXT, s.len(), out);
+                out.extend_from_slice(s.as_bytes());
+            } else {
+                encode_header(JSONB_TEXTJ, escaped.len(), out);
+                out.extend_from_slice(escaped.as_bytes());
+            }
+        }
+        serde_json::Value::Array(items) => {
+            let mut payload = Vec::new();
+            for item in items {
+                encode_element(item, &mut payload);
+            }
+            encode_header(JSONB_ARRAY, payload.len(), out);
+            out.extend_from_slice(&payload);
+        }
+        serde_json::Value::Object(map) => {
+            let mut payload = Vec::new();
+            for (key, value) in map {
+                encode_element(&serde_json::Value::String(key.clone()), &mut payload);
+                encode_element(value, &mut payload);
+            }
+            encode_header(JSONB_OBJECT, payload.len(), out);
+            out.extend_from_slice(&payload);
+        }
+    }
+}
+
+fn decode_element(bytes: &[u8]) -> deserialize::Result<(serde_json::Value, &[u8])> {
+    let header = *bytes
+        .first()
+        .ok_or("Unexpected end of JSONB value while reading header")?;
+    let tag = header & 0x0F;
+    let size_indicator = header >> 4;
+    let (len, rest) = match size_indicator {
+        0..=11 => (size_indicator as usize, &bytes[1..]),
+        12 => {
+            let len = *bytes
+                .get(1)
+                .ok_or("Unexpected end of JSONB value while reading 1-byte length")?;
+            (len as usize, &bytes[2..])
+        }
+        13 => {
+            let len = bytes
+                .get(1..3)
+                .ok_or("Unexpected end of JSONB value while reading 2-byte length")?;
+            (u16::from_be_bytes([len[0], len[1]]) as usize, &bytes[3..])
+        }
+        14 => {
+            let len = bytes
+                .get(1..5)
+                .ok_or("Unexpected end of JSONB value while reading 4-byte length")?;
+            (
+                u32::from_be_bytes([len[0], len[1], len[2], len[3]]) as usize,
+                &bytes[5..],
+            )
+        }
+        _ => return Err(format!("Unsupported JSONB length indicator: {size_indicator}").into()),
+    };
+    let payload = rest
+        .get(..len)
+        .ok_or("JSONB payload shorter than its declared length")?;
+    let remainder = &rest[len..];
+
+    let value = match tag {
+        JSONB_NULL => serde_json::Value::Null,
+        JSONB_TRUE => serde_json::Value::Bool(true),
+        JSONB_FALSE => serde_json::Value::Bool(false),
+        JSONB_INT | JSONB_FLOAT | JSONB_INT5 | JSONB_FLOAT5 => {
+            let text = std::str::from_utf8(payload)
+                .map_err(|_| "JSONB number payload was not valid UTF-8")?;
+            parse_jsonb_number(text)?
+        }
+        JSONB_TEXT | JSONB_TEXTRAW => {
+            let text = std::str::from_utf8(payload)
+                .map_err(|_| "JSONB text payload was not valid UTF-8")?;
+            serde_json::Value::String(text.to_owned())
+        }
+        JSONB_TEXTJ | JSONB_TEXT5 => serde_json::Value::String(json_unescape_body(payload)?),
+        JSONB_ARRAY => {
+            let mut items = Vec::new();
+            let mut remaining = payload;
+            while !remaining.is_empty() {
+                let (item, rest) = decode_element(remaining)?;
+                items.push(item);
+                remaining = rest;
+            }
+            serde_json::Value::Array(items)
+        }
+        JSONB_OBJECT => {
+            let mut map = serde_json::Map::new();
+            let mut remaining = payload;
+            while !remaining.is_empty() {
+                let (key, rest) = decode_element(remaining)?;
+                let key = match key {
+                    serde_json::Value::String(key) => key,
+                    _ => return Err("JSONB object key was not a text element".into()),
+                };
+                let (value, rest) = decode_element(rest)?;
+                map.insert(key, value);
+                remaining = rest;
+            }
+            serde_json::Value::Object(map)
+        }
+        _ => return Err(format!("Unsupported JSONB element tag: {tag}").into()),
+    };
+    Ok((value, remainder))
+}
+
+/// Returns the JSON-escaped form of `s`, without the surrounding quotes.
+///
+/// Comparing the result against `s` tells us whether `s` can be stored as
+/// plain TEXT(7) (no bytes need escaping) or must be stored as TEXTJ(8).
+fn json_escape_body(s: &str) -> String {
+    let quoted = serde_json::to_string(s).expect("a string always serializes to valid JSON");
+    quoted[1..quoted.len() - 1].to_owned()
+}
+
+/// Reverses [`json_escape_body`]: decodes a TEXTJ/TEXT5 payload (JSON-escaped
+/// text without the surrounding quotes) back into the original string.
+///
+/// Only JSON escapes are understood: a TEXT5 payload that uses a JSON5-only
+/// escape (such as `\x41`, `\'` or a line continuation) is rejected with an
+/// error.
+fn json_unescape_body(payload: &[u8]) -> deserialize::Result<String> {
+    let text =
+        std::str::from_utf8(payload).map_err(|_| "JSONB text payload was not valid UTF-8")?;
+    let quoted = format!("\"{text}\"");
+    serde_json::from_str(&quoted).map_err(|e| format!("Invalid JSONB escaped text: {e}").into())
+}
+
+/// Parses an INT/FLOAT/INT5/FLOAT5 payload. INT5/FLOAT5 payloads may use
+/// JSON5 numeric syntax that `serde_json` does not accept directly; of that
+/// syntax, a leading `+` and `0x`-prefixed hex integers that fit in an `i64`
+/// are normalized before parsing. Other JSON5 forms (such as `.5`, `-0x1F`
+/// or `Infinity`) are rejected with an error.
+fn parse_jsonb_number(text: &str) -> deserialize::Result<serde_json::Value> {
+    if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
+        return Ok(value);
+    }
+
+    let unsigned = text.strip_prefix('+').unwrap_or(text);
+    if let Some(hex) = unsigned
+        .strip_prefix("0x")
+        .or_else(|| unsigned.strip_prefix("0X"))
+    {
+        if let Ok(n) = i64::from_str_radix(hex, 16) {
+            return Ok(serde_json::Value::Number(n.into()));
+        }
+    }
+    if unsigned != text {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(unsigned) {
+            return Ok(value);
+        }
+    }
+
+    Err(format!("Invalid JSONB number literal: {text}").into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(value: serde_json::Value) {
+        let mut bytes = Vec::new();
+        encode_element(&value, &mut bytes);
+        let (decoded, rest) = decode_element(&bytes).expect("decode should succeed");
+        assert!(rest.is_empty());
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn roundtrips_scalars() {
+        roundtrip(serde_json::Value::Null);
+        roundtrip(serde_json::Value::Bool(true));
+        roundtrip(serde_json::Value::Bool(false));
+        roundtrip(serde_json::json!(42));
+        roundtrip(serde_json::json!(-17));
+        roundtrip(serde_json::json!(1.5));
+    }
+
+    #[test]
+    fn roundtrips_plain_string_as_text() {
+        let value = serde_json::json!("hello world");
+        let mut bytes = Vec::new();
+        encode_element(&value, &mut bytes);
+        assert_eq!(bytes[0] & 0x0F, JSONB_TEXT);
+        roundtrip(value);
+    }
+
+    #[test]
+    fn roundtrips_escaped_string_as_textj() {
+        let value = serde_json::json!("a\"b\nc");
+        let mut bytes = Vec::new();
+        encode_element(&value, &mut bytes);
+        assert_eq!(bytes[0] & 0x0F, JSONB_TEXTJ);
+        roundtrip(value);
+    }
+
+    #[test]
+    fn roundtrips_arrays_and_objects() {
+        roundtrip(serde_json::json!([1, "two", {"three": 3.0}, null, true, false]));
+    }
+
+    #[test]
+    fn decodes_textj_payload() {
+        // TEXTJ(8), length 4, payload `a\nb` escaped as `a\nb` (4 bytes: a, \, n, b)
+        let mut bytes = vec![JSONB_TEXTJ | (4 << 4)];
+        bytes.extend_from_slice(b"a\\nb");
+        let (decoded, rest) = decode_element(&bytes).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(decoded, serde_json::json!("a\nb"));
+    }
+
+    #[test]
+    fn decodes_int5_hex_payload() {
+        // INT5(4), length 4, payload `0x1F`
+        let mut bytes = vec![JSONB_INT5 | (4 << 4)];
+        bytes.extend_from_slice(b"0x1F");
+        let (decoded, rest) = decode_element(&bytes).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(decoded, serde_json::json!(31));
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        assert!(decode_element(&[]).is_err());
+    }
+
+    #[test]
+    fn rejects_payload_shorter_than_declared_length() {
+        // TEXT(7) claims 5 bytes but only 2 are present.
+        let bytes = vec![JSONB_TEXT | (5 << 4), b'h', b'i'];
+        assert!(decode_element(&bytes).is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_tag() {
+        // Tag 13 is not a defined JSONB element type.
+        let bytes = vec![13];
+        assert!(decode_element(&bytes).is_err());
+    }
+}
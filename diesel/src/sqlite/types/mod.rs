@@ -56,6 +56,29 @@ impl Queryable<sql_types::Binary, Sqlite> for *const [u8] {
     }
 }
 
+/// Stores `url::Url` values as Text, round-tripping through `Url::parse` on read
+/// so that columns can hold URLs directly instead of a hand-rolled `String`.
+///
+/// Requires the `url` feature, which pulls in the `url` crate as an optional
+/// dependency the same way `chrono` and `time` are wired in for the temporal
+/// impls elsewhere in this module (`url = { version = "2", optional = true }`
+/// in `Cargo.toml`, surfaced as `url = ["dep:url"]`).
+#[cfg(all(feature = "sqlite", feature = "url"))]
+impl FromSql<sql_types::Text, Sqlite> for url::Url {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let text = value.read_text();
+        url::Url::parse(text).map_err(|e| e.into())
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "url"))]
+impl ToSql<sql_types::Text, Sqlite> for url::Url {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.as_str());
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(feature = "sqlite")]
 #[allow(clippy::cast_possible_truncation)] // we want to truncate here
 impl FromSql<sql_types::SmallInt, Sqlite> for i16 {
@@ -124,6 +147,129 @@ impl ToSql<sql_types::Binary, Sqlite> for [u8] {
     }
 }
 
+/// A BLOB of `N` zero-filled bytes.
+///
+/// Binding a `ZeroBlob` asks SQLite to reserve the space for an `N`-byte BLOB
+/// (via `sqlite3_bind_zeroblob`) without ever materializing an `[u8]` buffer
+/// on the Rust side. The reserved space can later be filled in place, which
+/// makes this the natural starting point for incremental blob I/O on large
+/// binary columns.
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroBlob(pub i32);
+
+#[cfg(feature = "sqlite")]
+impl ToSql<sql_types::Binary, Sqlite> for ZeroBlob {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        if !zero_blob_length_is_valid(self.0) {
+            return Err("ZeroBlob length must not be negative".into());
+        }
+        out.set_value(*self);
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn zero_blob_length_is_valid(len: i32) -> bool {
+    len >= 0
+}
+
+/// Binds a `ZeroBlob` as a "zeroblob of length N" bind value so the statement
+/// binder can translate it into `sqlite3_bind_zeroblob` instead of copying bytes.
+#[cfg(feature = "sqlite")]
+impl<'a> From<ZeroBlob> for super::connection::SqliteBindValue<'a> {
+    fn from(value: ZeroBlob) -> Self {
+        super::connection::SqliteBindValue::Zeroblob(value.0)
+    }
+}
+
+/// Reads the 16 octets of a stored IP address, rejecting a BLOB of any other
+/// length.
+///
+/// `std::net::IpAddr` is stored as a 16-byte BLOB, normalizing every address to
+/// its IPv6 representation (an IPv4 address `a.b.c.d` is mapped to
+/// `::ffff:a.b.c.d`) so that IPv4 and IPv6 values sort and compare uniformly
+/// within a single column.
+#[cfg(feature = "sqlite")]
+fn ip_addr_octets_from_blob(bytes: &[u8]) -> deserialize::Result<[u8; 16]> {
+    <[u8; 16]>::try_from(bytes).map_err(|_| {
+        format!(
+            "Expected exactly 16 bytes for an IP address, got {} bytes",
+            bytes.len()
+        )
+        .into()
+    })
+}
+
+#[cfg(feature = "sqlite")]
+fn ip_addr_from_ipv6_octets(octets: [u8; 16]) -> std::net::IpAddr {
+    let ipv6 = std::net::Ipv6Addr::from(octets);
+    match ipv6.to_ipv4_mapped() {
+        Some(ipv4) => std::net::IpAddr::V4(ipv4),
+        None => std::net::IpAddr::V6(ipv6),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn ip_addr_to_ipv6_octets(addr: std::net::IpAddr) -> [u8; 16] {
+    match addr {
+        std::net::IpAddr::V4(v4) => v4.to_ipv6_mapped().octets(),
+        std::net::IpAddr::V6(v6) => v6.octets(),
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl FromSql<sql_types::Binary, Sqlite> for std::net::IpAddr {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let octets = ip_addr_octets_from_blob(value.read_blob())?;
+        Ok(ip_addr_from_ipv6_octets(octets))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ToSql<sql_types::Binary, Sqlite> for std::net::IpAddr {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(ip_addr_to_ipv6_octets(*self).to_vec());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl FromSql<sql_types::Binary, Sqlite> for std::net::Ipv4Addr {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        match <std::net::IpAddr as FromSql<sql_types::Binary, Sqlite>>::from_sql(value)? {
+            std::net::IpAddr::V4(v4) => Ok(v4),
+            std::net::IpAddr::V6(v6) => {
+                Err(format!("Expected an IPv4 address, got IPv6 address {v6}").into())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ToSql<sql_types::Binary, Sqlite> for std::net::Ipv4Addr {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(ip_addr_to_ipv6_octets(std::net::IpAddr::V4(*self)).to_vec());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl FromSql<sql_types::Binary, Sqlite> for std::net::Ipv6Addr {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let octets = ip_addr_octets_from_blob(value.read_blob())?;
+        Ok(std::net::Ipv6Addr::from(octets))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl ToSql<sql_types::Binary, Sqlite> for std::net::Ipv6Addr {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.octets().to_vec());
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(feature = "sqlite")]
 impl ToSql<sql_types::SmallInt, Sqlite> for i16 {
     fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
@@ -216,6 +362,138 @@ impl ToSql<sql_types::Double, Sqlite> for f64 {
 #[cfg(feature = "sqlite")]
 pub struct Timestamptz;
 
+/// A Unix-epoch timestamp, stored as an `INTEGER` count of seconds since
+/// `1970-01-01 00:00:00 UTC`.
+///
+/// This is an alternative to [`Timestamptz`], which stores the same
+/// information as `Text`. Integer storage is smaller and faster to
+/// range-query, at the cost of being less human-readable when inspecting
+/// the database directly.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
+/// - [`chrono::DateTime`]`<Utc>` with `feature = "chrono"`
+/// - [`time::PrimitiveDateTime`] with `feature = "time"`
+/// - [`time::OffsetDateTime`] with `feature = "time"`
+///
+/// ### [`FromSql`] impls
+///
+/// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
+/// - [`chrono::DateTime`]`<Utc>` with `feature = "chrono"`
+/// - [`time::PrimitiveDateTime`] with `feature = "time"`
+/// - [`time::OffsetDateTime`] with `feature = "time"`
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+#[cfg_attr(
+    feature = "chrono",
+    doc = " [`chrono::NaiveDateTime`]: chrono::naive::NaiveDateTime"
+)]
+#[cfg_attr(
+    not(feature = "chrono"),
+    doc = " [`chrono::NaiveDateTime`]: https://docs.rs/chrono/0.4.19/chrono/naive/struct.NaiveDateTime.html"
+)]
+#[cfg_attr(feature = "chrono", doc = " [`chrono::DateTime`]: chrono::DateTime")]
+#[cfg_attr(
+    not(feature = "chrono"),
+    doc = " [`chrono::DateTime`]: https://docs.rs/chrono/0.4.19/chrono/struct.DateTime.html"
+)]
+#[cfg_attr(
+    feature = "time",
+    doc = " [`time::PrimitiveDateTime`]: time::PrimitiveDateTime"
+)]
+#[cfg_attr(
+    not(feature = "time"),
+    doc = " [`time::PrimitiveDateTime`]: https://docs.rs/time/0.3.9/time/struct.PrimitiveDateTime.html"
+)]
+#[cfg_attr(
+    feature = "time",
+    doc = " [`time::OffsetDateTime`]: time::OffsetDateTime"
+)]
+#[cfg_attr(
+    not(feature = "time"),
+    doc = " [`time::OffsetDateTime`]: https://docs.rs/time/0.3.9/time/struct.OffsetDateTime.html"
+)]
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[diesel(sqlite_type(name = "BigInt"))]
+#[cfg(feature = "sqlite")]
+pub struct UnixEpochTimestamp;
+
+#[cfg(all(feature = "sqlite", feature = "chrono"))]
+impl ToSql<UnixEpochTimestamp, Sqlite> for chrono::NaiveDateTime {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.and_utc().timestamp());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "chrono"))]
+impl ToSql<UnixEpochTimestamp, Sqlite> for chrono::DateTime<chrono::Utc> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.timestamp());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn naive_datetime_from_unix_timestamp(seconds: i64) -> deserialize::Result<chrono::NaiveDateTime> {
+    chrono::DateTime::from_timestamp(seconds, 0)
+        .map(|dt| dt.naive_utc())
+        .ok_or_else(|| format!("Invalid unix-epoch timestamp: {seconds}").into())
+}
+
+#[cfg(all(feature = "sqlite", feature = "chrono"))]
+impl FromSql<UnixEpochTimestamp, Sqlite> for chrono::NaiveDateTime {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        naive_datetime_from_unix_timestamp(value.read_long())
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "chrono"))]
+impl FromSql<UnixEpochTimestamp, Sqlite> for chrono::DateTime<chrono::Utc> {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        <chrono::NaiveDateTime as FromSql<UnixEpochTimestamp, Sqlite>>::from_sql(value)
+            .map(|naive| chrono::DateTime::from_naive_utc_and_offset(naive, chrono::Utc))
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "time"))]
+impl ToSql<UnixEpochTimestamp, Sqlite> for time::PrimitiveDateTime {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.assume_utc().unix_timestamp());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "time"))]
+impl ToSql<UnixEpochTimestamp, Sqlite> for time::OffsetDateTime {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.unix_timestamp());
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "time")]
+fn offset_date_time_from_unix_timestamp(seconds: i64) -> deserialize::Result<time::OffsetDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(seconds).map_err(|e| e.into())
+}
+
+#[cfg(all(feature = "sqlite", feature = "time"))]
+impl FromSql<UnixEpochTimestamp, Sqlite> for time::OffsetDateTime {
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        offset_date_time_from_unix_timestamp(value.read_long())
+    }
+}
+
+#[cfg(all(feature = "sqlite", feature = "time"))]
+impl FromSql<UnixEpochTimestamp, Sqlite> for time::PrimitiveDateTime {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let odt = <time::OffsetDateTime as FromSql<UnixEpochTimestamp, Sqlite>>::from_sql(value)?;
+        Ok(time::PrimitiveDateTime::new(odt.date(), odt.time()))
+    }
+}
+
 /// The SQL type for JSON validation flags
 ///
 /// This type is backed by an Integer in SQLite.
@@ -307,3 +585,68 @@ impl<'a> AsExpression<JsonValidFlags> for &'a i32 {
         crate::expression::bound::Bound::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_blob_rejects_negative_length() {
+        assert!(!zero_blob_length_is_valid(-1));
+        assert!(!zero_blob_length_is_valid(i32::MIN));
+    }
+
+    #[test]
+    fn zero_blob_accepts_non_negative_length() {
+        assert!(zero_blob_length_is_valid(0));
+        assert!(zero_blob_length_is_valid(1024));
+    }
+
+    #[test]
+    fn ip_addr_v4_roundtrips_through_ipv6_mapped_octets() {
+        let addr: std::net::IpAddr = "192.0.2.1".parse().unwrap();
+        let octets = ip_addr_to_ipv6_octets(addr);
+        assert_eq!(octets, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 192, 0, 2, 1]);
+        assert_eq!(ip_addr_from_ipv6_octets(octets), addr);
+    }
+
+    #[test]
+    fn ip_addr_v6_roundtrips_through_octets() {
+        let addr: std::net::IpAddr = "2001:db8::1".parse().unwrap();
+        let octets = ip_addr_to_ipv6_octets(addr);
+        assert_eq!(ip_addr_from_ipv6_octets(octets), addr);
+    }
+
+    #[test]
+    fn ip_addr_from_blob_rejects_wrong_length() {
+        assert!(ip_addr_octets_from_blob(&[0; 15]).is_err());
+        assert!(ip_addr_octets_from_blob(&[0; 17]).is_err());
+        assert!(ip_addr_octets_from_blob(&[0; 16]).is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn naive_datetime_from_unix_timestamp_accepts_epoch() {
+        let dt = naive_datetime_from_unix_timestamp(0).unwrap();
+        assert_eq!(dt, chrono::DateTime::from_timestamp(0, 0).unwrap().naive_utc());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn naive_datetime_from_unix_timestamp_rejects_out_of_range_seconds() {
+        assert!(naive_datetime_from_unix_timestamp(i64::MAX).is_err());
+        assert!(naive_datetime_from_unix_timestamp(i64::MIN).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time_from_unix_timestamp_accepts_epoch() {
+        assert!(offset_date_time_from_unix_timestamp(0).is_ok());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn offset_date_time_from_unix_timestamp_rejects_out_of_range_seconds() {
+        assert!(offset_date_time_from_unix_timestamp(i64::MAX).is_err());
+    }
+}